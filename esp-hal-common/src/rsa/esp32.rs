@@ -0,0 +1,90 @@
+//! RSA accelerator support specific to the original ESP32.
+//!
+//! Unlike the ESP32-C3/C6/H2/S2/S3, the ESP32's RSA block has no auto-mode:
+//! the operation to run (modular exponentiation, modular multiplication or
+//! large-number multiplication) must be selected by hand through the `mode`
+//! register before the start bit is set, and completion is only ever
+//! signalled through the interrupt status bit rather than a dedicated idle
+//! flag.
+
+use super::{
+    implement_op, Multi, Rsa, RsaMode, RsaModularExponentiation, RsaModularMultiplication,
+    RsaMultiplication,
+};
+
+/// Hardware-supported operand sizes for the ESP32 RSA accelerator: 512,
+/// 1024, 2048, 3072 and 4096 bits.
+pub mod operand_sizes {
+    use super::implement_op;
+
+    implement_op!(
+        (512, multi),
+        (1024, multi),
+        (2048, multi),
+        (3072, multi),
+        (4096, multi)
+    );
+}
+
+/// Selects which operation the next `set_start()` will trigger.
+enum Mode {
+    ModularExponentiation,
+    ModularMultiplication,
+    Multiplication,
+}
+
+impl<'d> Rsa<'d> {
+    fn select_mode(&mut self, mode: Mode) {
+        self.rsa.mode.write(|w| unsafe {
+            w.bits(match mode {
+                Mode::ModularExponentiation => 0,
+                Mode::ModularMultiplication => 1,
+                Mode::Multiplication => 2,
+            })
+        });
+    }
+
+    fn trigger_start(&mut self) {
+        self.rsa.start.write(|w| w.start().set_bit());
+    }
+
+    /// The ESP32 has no dedicated idle flag; completion is only visible
+    /// through the interrupt status bit.
+    pub(super) fn is_idle(&mut self) -> bool {
+        self.rsa.interrupt.read().interrupt().bit_is_set()
+    }
+
+    pub(super) fn clear_interrupt(&mut self) {
+        self.rsa.interrupt.write(|w| w.interrupt().set_bit());
+    }
+}
+
+impl<'a, 'd, T: RsaMode, const N: usize> RsaModularExponentiation<'a, 'd, T>
+where
+    T: RsaMode<InputType = [u8; N]>,
+{
+    pub(super) fn set_start(&mut self) {
+        self.rsa.select_mode(Mode::ModularExponentiation);
+        self.rsa.trigger_start();
+    }
+}
+
+impl<'a, 'd, T: RsaMode, const N: usize> RsaModularMultiplication<'a, 'd, T>
+where
+    T: RsaMode<InputType = [u8; N]>,
+{
+    pub(super) fn set_start(&mut self) {
+        self.rsa.select_mode(Mode::ModularMultiplication);
+        self.rsa.trigger_start();
+    }
+}
+
+impl<'a, 'd, T: RsaMode + Multi, const N: usize> RsaMultiplication<'a, 'd, T>
+where
+    T: RsaMode<InputType = [u8; N]>,
+{
+    pub(super) fn set_start(&mut self) {
+        self.rsa.select_mode(Mode::Multiplication);
+        self.rsa.trigger_start();
+    }
+}
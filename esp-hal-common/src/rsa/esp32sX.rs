@@ -0,0 +1,108 @@
+//! RSA accelerator configuration specific to the ESP32-S2/S3 family.
+
+use super::{
+    implement_op, Multi, Rsa, RsaMode, RsaModularExponentiation, RsaModularMultiplication,
+    RsaMultiplication,
+};
+
+/// Hardware-supported operand sizes for the ESP32-S2/S3 RSA accelerator:
+/// 512, 1024, 2048, 3072 and 4096 bits.
+pub mod operand_sizes {
+    use super::implement_op;
+
+    implement_op!(
+        (512, multi),
+        (1024, multi),
+        (2048, multi),
+        (3072, multi),
+        (4096, multi)
+    );
+}
+
+impl<'d> Rsa<'d> {
+    /// The ESP32-S2/S3 RSA accelerator has a dedicated idle flag that is set
+    /// automatically once the current operation completes.
+    pub(super) fn is_idle(&mut self) -> bool {
+        self.rsa.query_idle.read().query_idle().bit_is_set()
+    }
+
+    pub(super) fn clear_interrupt(&mut self) {
+        self.rsa.interrupt.write(|w| w.interrupt().set_bit());
+    }
+}
+
+impl<'a, 'd, T: RsaMode, const N: usize> RsaModularExponentiation<'a, 'd, T>
+where
+    T: RsaMode<InputType = [u8; N]>,
+{
+    /// Triggers the accelerator's dedicated modular exponentiation start
+    /// register; the auto-mode idle flag clears itself and sets again once
+    /// the operation completes.
+    pub(super) fn set_start(&mut self) {
+        self.rsa.rsa.mode_exp_start.write(|w| w.start().set_bit());
+    }
+}
+
+impl<'a, 'd, T: RsaMode, const N: usize> RsaModularMultiplication<'a, 'd, T>
+where
+    T: RsaMode<InputType = [u8; N]>,
+{
+    /// Triggers the accelerator's dedicated modular multiplication start
+    /// register; the auto-mode idle flag clears itself and sets again once
+    /// the operation completes.
+    pub(super) fn set_start(&mut self) {
+        self.rsa.rsa.modular_mult_start.write(|w| w.start().set_bit());
+    }
+}
+
+impl<'a, 'd, T: RsaMode + Multi, const N: usize> RsaMultiplication<'a, 'd, T>
+where
+    T: RsaMode<InputType = [u8; N]>,
+{
+    /// Triggers the accelerator's dedicated large-number multiplication
+    /// start register; the auto-mode idle flag clears itself and sets again
+    /// once the operation completes.
+    pub(super) fn set_start(&mut self) {
+        self.rsa.rsa.mult_start.write(|w| w.start().set_bit());
+    }
+}
+
+// The search-acceleration and constant-time controls are only present on the
+// ESP32-S3; the ESP32-S2's RSA block does not implement them.
+#[cfg(esp32s3)]
+impl<'a, 'd, T: RsaMode, const N: usize> RsaModularExponentiation<'a, 'd, T>
+where
+    T: RsaMode<InputType = [u8; N]>,
+{
+    /// Enables or disables the accelerator's constant-time mode for this
+    /// exponentiation. Constant-time mode is side-channel safe but slower;
+    /// it is enabled by default. Must be called before
+    /// [`Self::start_exponentiation`].
+    pub fn set_constant_time(&mut self, enable: bool) {
+        self.rsa
+            .rsa
+            .constant_time
+            .write(|w| w.constant_time().bit(!enable));
+    }
+
+    /// Enables the accelerator's search feature, which skips the leading
+    /// zero bits of the exponent starting at `start_bit` to speed up
+    /// operations with a known-short exponent (e.g. a small public
+    /// exponent). Must be called before [`Self::start_exponentiation`].
+    pub fn enable_search(&mut self, start_bit: u32) {
+        self.rsa.rsa.search.write(|w| w.search_enable().set_bit());
+        self.rsa
+            .rsa
+            .search_pos
+            .write(|w| unsafe { w.bits(start_bit) });
+    }
+
+    /// Disables the accelerator's search feature, restoring the default
+    /// behaviour of processing every bit of the exponent.
+    pub fn disable_search(&mut self) {
+        self.rsa
+            .rsa
+            .search
+            .write(|w| w.search_enable().clear_bit());
+    }
+}